@@ -1,5 +1,8 @@
-use rand::Rng;
-use std::{io::Write, thread, time::Duration, env};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::{env, fs::File, io::{BufWriter, Write}, thread, time::Duration};
 use termion::{color, style};
 
 #[derive(Clone, Copy, PartialEq)]
@@ -9,81 +12,63 @@ enum Cell {
     Solution,
     Current,
     Visited,
+    // Visited by the goal-side frontier of a bidirectional search; kept distinct from
+    // `Visited` so the two frontiers render in different colors as they converge.
+    VisitedBackward,
 }
 
+// Step cost for a "mud" terrain cell, versus 1 for ordinary path cells.
+const MUD_WEIGHT: usize = 3;
+
 struct Maze {
     size: usize,
     grid: Vec<Vec<Cell>>,
+    // Per-cell step cost; 1 everywhere except scattered mud cells. Only the heap-based
+    // solvers (Dijkstra, A*) read this — BFS/IDA* reason in hop counts, not cost.
+    weights: Vec<Vec<usize>>,
     start: (usize, usize),
     goal: (usize, usize),
+    diagonal: bool,
 }
 
 impl Maze {
-    fn new(size: usize) -> Self {
+    fn new(size: usize, diagonal: bool) -> Self {
         let mut grid = vec![vec![Cell::Wall; size]; size];
         // Start at top-left, goal at bottom-right
         let start = (1, 1);
         let goal = (size - 2, size - 2);
         grid[start.0][start.1] = Cell::Path;
         grid[goal.0][goal.1] = Cell::Path;
-        
+
         Maze {
             size,
             grid,
+            weights: vec![vec![1; size]; size],
             start,
             goal,
+            diagonal,
         }
     }
 
-    fn generate(&mut self) {
-        let mut rng = rand::thread_rng();
-        let mut stack = vec![self.start];
-        let mut visited = vec![vec![false; self.size]; self.size];
-        visited[self.start.0][self.start.1] = true;
-        
-        // First, generate a full maze using DFS with randomized neighbor selection
-        while let Some(&current) = stack.last() {
-            let mut neighbors = Vec::new();
-            for (dx, dy) in &[(0, 2), (2, 0), (0, -2), (-2, 0)] {
-                let nx = (current.0 as isize + dx) as usize;
-                let ny = (current.1 as isize + dy) as usize;
-                if nx < self.size - 1 && ny < self.size - 1 && !visited[nx][ny] {
-                    neighbors.push((nx, ny));
-                }
-            }
+    // Carves the maze with `generator`, then applies the post-processing every generator
+    // shares: opening start/goal, scattering mud, and repairing connectivity if needed.
+    fn generate_with(&mut self, generator: &dyn Generator, rng: &mut StdRng) {
+        generator.generate(self, rng);
 
-            if neighbors.is_empty() {
-                stack.pop();
-            } else {
-                // Randomly choose next cell
-                let (nx, ny) = neighbors[rng.gen_range(0..neighbors.len())];
-                self.grid[nx][ny] = Cell::Path;
-                self.grid[(current.0 + nx) / 2][(current.1 + ny) / 2] = Cell::Path;
-                visited[nx][ny] = true;
-                stack.push((nx, ny));
-            }
-        }
+        // Ensure start and goal are open
+        self.grid[self.start.0][self.start.1] = Cell::Path;
+        self.grid[self.goal.0][self.goal.1] = Cell::Path;
 
-        // Add some random additional connections to create loops and multiple paths
-        for _ in 0..self.size {
-            let x = rng.gen_range(1..self.size-1);
-            let y = rng.gen_range(1..self.size-1);
-            if self.grid[x][y] == Cell::Path {
-                // Try to break a random wall
-                let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
-                let (dx, dy) = directions[rng.gen_range(0..directions.len())];
-                let nx = (x as isize + dx) as usize;
-                let ny = (y as isize + dy) as usize;
-                if nx < self.size - 1 && ny < self.size - 1 && self.grid[nx][ny] == Cell::Wall {
-                    self.grid[nx][ny] = Cell::Path;
-                }
+        // Scatter a few weighted "mud" cells that cost more to cross, so the heap-based
+        // solvers have terrain worth routing around.
+        for _ in 0..self.size / 2 {
+            let x = rng.gen_range(1..self.size - 1);
+            let y = rng.gen_range(1..self.size - 1);
+            if self.grid[x][y] == Cell::Path && (x, y) != self.start && (x, y) != self.goal {
+                self.weights[x][y] = MUD_WEIGHT;
             }
         }
 
-        // Ensure start and goal are open
-        self.grid[self.start.0][self.start.1] = Cell::Path;
-        self.grid[self.goal.0][self.goal.1] = Cell::Path;
-        
         // Verify maze is solvable using DFS
         if !self.is_solvable() {
             // If not solvable, connect goal to nearest path
@@ -115,7 +100,7 @@ impl Maze {
             for (dx, dy) in &[(0, 1), (1, 0), (0, -1), (-1, 0)] {
                 let nx = (x as isize + dx) as usize;
                 let ny = (y as isize + dy) as usize;
-                if nx < self.size && ny < self.size && 
+                if nx < self.size && ny < self.size &&
                    !visited[nx][ny] && self.grid[nx][ny] == Cell::Path {
                     stack.push((nx, ny));
                     visited[nx][ny] = true;
@@ -130,16 +115,114 @@ impl Maze {
          (pos.1 as isize - self.goal.1 as isize).abs()) as usize
     }
 
+    // Octile distance with D1 == D2 == 1 (our uniform step cost), which collapses to
+    // Chebyshev distance (max(dx, dy)).
+    fn octile_distance(&self, pos: (usize, usize)) -> usize {
+        let dx = (pos.0 as isize - self.goal.0 as isize).abs();
+        let dy = (pos.1 as isize - self.goal.1 as isize).abs();
+        const D1: isize = 1;
+        const D2: isize = 1;
+        ((dx + dy) + (D2 - 2 * D1) * dx.min(dy)) as usize
+    }
+
+    // The heuristic the solvers should use for the maze's current movement mode, so that A*
+    // and IDA* stay admissible whether or not diagonal steps are allowed.
+    fn heuristic(&self, pos: (usize, usize)) -> usize {
+        if self.diagonal {
+            self.octile_distance(pos)
+        } else {
+            self.manhattan_distance(pos)
+        }
+    }
+
+    // In-bounds, non-wall neighbors reachable from `pos` via a single step; includes the four
+    // diagonal offsets when diagonal movement is enabled.
+    fn neighbors(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        let mut offsets: Vec<(isize, isize)> = vec![(0, 1), (1, 0), (0, -1), (-1, 0)];
+        if self.diagonal {
+            offsets.extend_from_slice(&[(1, 1), (1, -1), (-1, 1), (-1, -1)]);
+        }
+
+        let mut result = Vec::new();
+        for (dx, dy) in offsets {
+            let nx = (pos.0 as isize + dx) as usize;
+            let ny = (pos.1 as isize + dy) as usize;
+            if nx >= self.size || ny >= self.size || self.grid[nx][ny] == Cell::Wall {
+                continue;
+            }
+
+            // A diagonal step may not cut through a wall corner: at least one of the two
+            // orthogonal cells flanking it must be open.
+            let cuts_corner = dx != 0 && dy != 0
+                && self.grid[nx][pos.1] == Cell::Wall
+                && self.grid[pos.0][ny] == Cell::Wall;
+            if cuts_corner {
+                continue;
+            }
+
+            result.push((nx, ny));
+        }
+        result
+    }
+
+    // Maps a grid cell to an RGB color for `write_image`, marking start/goal distinctly from
+    // the rest of the solver's state colors.
+    fn cell_color(&self, pos: (usize, usize)) -> (u8, u8, u8) {
+        if pos == self.start {
+            return (220, 20, 20); // start: red
+        }
+        if pos == self.goal {
+            return (220, 20, 220); // goal: magenta
+        }
+        match self.grid[pos.0][pos.1] {
+            Cell::Wall => (10, 10, 90),        // dark blue
+            Cell::Path if self.weights[pos.0][pos.1] > 1 => (165, 113, 63), // mud
+            Cell::Path => (255, 255, 255),     // white
+            Cell::Solution => (30, 180, 30),   // green
+            Cell::Visited => (255, 245, 200),  // faint trail
+            Cell::VisitedBackward => (200, 230, 255), // faint trail, goal side
+            Cell::Current => (220, 20, 20),
+        }
+    }
+
+    // Renders the grid to a binary PPM (P6) image, scaling each cell to a `cell_px` pixel
+    // block so large mazes stay legible. A natural companion to the ANSI `display()`
+    // animation for sharing or diffing solved mazes as static artifacts.
+    fn write_image(&self, path: &str, cell_px: usize) -> std::io::Result<()> {
+        let width = self.size * cell_px;
+        let height = self.size * cell_px;
+        let mut file = BufWriter::new(File::create(path)?);
+        write!(file, "P6\n{} {}\n255\n", width, height)?;
+
+        for x in 0..self.size {
+            let mut row_pixels = Vec::with_capacity(width * 3);
+            for y in 0..self.size {
+                let (r, g, b) = self.cell_color((x, y));
+                for _ in 0..cell_px {
+                    row_pixels.extend_from_slice(&[r, g, b]);
+                }
+            }
+            for _ in 0..cell_px {
+                file.write_all(&row_pixels)?;
+            }
+        }
+        Ok(())
+    }
+
     fn display(&self) {
         print!("\x1B[H");  // Move cursor to top-left
-        for row in &self.grid {
-            for &cell in row {
+        for (x, row) in self.grid.iter().enumerate() {
+            for (y, &cell) in row.iter().enumerate() {
                 let symbol = match cell {
                     Cell::Wall => format!("{}█{}", color::Fg(color::Blue), style::Reset),
+                    Cell::Path if self.weights[x][y] > 1 => {
+                        format!("{}▒{}", color::Fg(color::Rgb(165, 113, 63)), style::Reset)
+                    }
                     Cell::Path => " ".to_string(),
                     Cell::Solution => format!("{}•{}", color::Fg(color::Green), style::Reset),
                     Cell::Current => format!("{}@{}", color::Fg(color::Red), style::Reset),
                     Cell::Visited => format!("{}·{}", color::Fg(color::Yellow), style::Reset),
+                    Cell::VisitedBackward => format!("{}·{}", color::Fg(color::Cyan), style::Reset),
                 };
                 print!("{}", symbol);
             }
@@ -149,10 +232,450 @@ impl Maze {
     }
 }
 
+trait Generator {
+    fn generate(&self, maze: &mut Maze, rng: &mut StdRng);
+}
+
+// A pair of lattice positions used by the generators below: in Prim, a room and the wall
+// between it and the visited region; in Kruskal, two adjacent rooms.
+type RoomWall = ((usize, usize), (usize, usize));
+
+/// The original generator: a randomized depth-first carve through the half-resolution room
+/// lattice, followed by knocking down a few extra walls to introduce loops and multiple paths.
+/// This produces the long, winding corridors characteristic of DFS mazes.
+struct DfsGenerator;
+
+impl Generator for DfsGenerator {
+    fn generate(&self, maze: &mut Maze, rng: &mut StdRng) {
+        let mut stack = vec![maze.start];
+        let mut visited = vec![vec![false; maze.size]; maze.size];
+        visited[maze.start.0][maze.start.1] = true;
+
+        // First, generate a full maze using DFS with randomized neighbor selection
+        while let Some(&current) = stack.last() {
+            let mut neighbors = Vec::new();
+            for (dx, dy) in &[(0, 2), (2, 0), (0, -2), (-2, 0)] {
+                let nx = (current.0 as isize + dx) as usize;
+                let ny = (current.1 as isize + dy) as usize;
+                if nx < maze.size - 1 && ny < maze.size - 1 && !visited[nx][ny] {
+                    neighbors.push((nx, ny));
+                }
+            }
+
+            if neighbors.is_empty() {
+                stack.pop();
+            } else {
+                // Randomly choose next cell
+                let (nx, ny) = neighbors[rng.gen_range(0..neighbors.len())];
+                maze.grid[nx][ny] = Cell::Path;
+                maze.grid[(current.0 + nx) / 2][(current.1 + ny) / 2] = Cell::Path;
+                visited[nx][ny] = true;
+                stack.push((nx, ny));
+            }
+        }
+
+        // Add some random additional connections to create loops and multiple paths
+        for _ in 0..maze.size {
+            let x = rng.gen_range(1..maze.size - 1);
+            let y = rng.gen_range(1..maze.size - 1);
+            if maze.grid[x][y] == Cell::Path {
+                // Try to break a random wall
+                let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+                let (dx, dy) = directions[rng.gen_range(0..directions.len())];
+                let nx = (x as isize + dx) as usize;
+                let ny = (y as isize + dy) as usize;
+                if nx < maze.size - 1 && ny < maze.size - 1 && maze.grid[nx][ny] == Cell::Wall {
+                    maze.grid[nx][ny] = Cell::Path;
+                }
+            }
+        }
+    }
+}
+
+/// Randomized Prim's algorithm over the same half-resolution room lattice: grows a single
+/// connected region one room at a time by picking a random wall off its frontier. Tends to
+/// produce many short dead-ends rather than DFS's long corridors.
+struct PrimGenerator;
+
+impl Generator for PrimGenerator {
+    fn generate(&self, maze: &mut Maze, rng: &mut StdRng) {
+        let mut visited = vec![vec![false; maze.size]; maze.size];
+        visited[maze.start.0][maze.start.1] = true;
+
+        // Frontier entries: a not-yet-visited room, paired with the wall that would connect
+        // it to the visited region.
+        let mut frontier: Vec<RoomWall> = Vec::new();
+        push_prim_frontier(maze, maze.start, &visited, &mut frontier);
+
+        while !frontier.is_empty() {
+            let idx = rng.gen_range(0..frontier.len());
+            let (room, wall) = frontier.swap_remove(idx);
+
+            if visited[room.0][room.1] {
+                continue;
+            }
+            visited[room.0][room.1] = true;
+
+            maze.grid[wall.0][wall.1] = Cell::Path;
+            maze.grid[room.0][room.1] = Cell::Path;
+
+            push_prim_frontier(maze, room, &visited, &mut frontier);
+        }
+    }
+}
+
+// Queues every unvisited room reachable from `room` as a frontier candidate, alongside the
+// wall cell that would need to open to connect it.
+fn push_prim_frontier(
+    maze: &Maze,
+    room: (usize, usize),
+    visited: &[Vec<bool>],
+    frontier: &mut Vec<RoomWall>,
+) {
+    for (dx, dy) in &[(0, 2), (2, 0), (0, -2), (-2, 0)] {
+        let nx = (room.0 as isize + dx) as usize;
+        let ny = (room.1 as isize + dy) as usize;
+        if nx < maze.size - 1 && ny < maze.size - 1 && !visited[nx][ny] {
+            let wall = ((room.0 + nx) / 2, (room.1 + ny) / 2);
+            frontier.push(((nx, ny), wall));
+        }
+    }
+}
+
+// Finds the representative of `x`'s set, compressing the path as it walks up.
+fn find_root(parent: &mut [usize], x: usize) -> usize {
+    if parent[x] != x {
+        parent[x] = find_root(parent, parent[x]);
+    }
+    parent[x]
+}
+
+/// Kruskal's algorithm via union-find: shuffles every candidate wall between adjacent rooms,
+/// then opens each one whose two rooms aren't already connected. The seeded rng drives the
+/// shuffle, so a given seed reproduces the same maze.
+struct KruskalGenerator;
+
+impl Generator for KruskalGenerator {
+    fn generate(&self, maze: &mut Maze, rng: &mut StdRng) {
+        let mut parent: Vec<usize> = (0..maze.size * maze.size).collect();
+
+        let mut walls: Vec<RoomWall> = Vec::new();
+        let mut x = 1;
+        while x < maze.size - 1 {
+            let mut y = 1;
+            while y < maze.size - 1 {
+                maze.grid[x][y] = Cell::Path;
+                for (dx, dy) in &[(0, 2), (2, 0)] {
+                    let nx = (x as isize + dx) as usize;
+                    let ny = (y as isize + dy) as usize;
+                    if nx < maze.size - 1 && ny < maze.size - 1 {
+                        walls.push(((x, y), (nx, ny)));
+                    }
+                }
+                y += 2;
+            }
+            x += 2;
+        }
+
+        // Fisher-Yates shuffle driven by the seeded rng, so runs are reproducible.
+        for i in (1..walls.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            walls.swap(i, j);
+        }
+
+        for (a, b) in walls {
+            let (root_a, root_b) = (
+                find_root(&mut parent, a.0 * maze.size + a.1),
+                find_root(&mut parent, b.0 * maze.size + b.1),
+            );
+            if root_a != root_b {
+                parent[root_a] = root_b;
+                let wall = ((a.0 + b.0) / 2, (a.1 + b.1) / 2);
+                maze.grid[wall.0][wall.1] = Cell::Path;
+            }
+        }
+    }
+}
+
+/// Walks a predecessor grid from `goal` back to `start`, reversing it into a start-to-goal path.
+fn reconstruct_path(
+    came_from: &[Vec<Option<(usize, usize)>>],
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Vec<(usize, usize)> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = came_from[current.0][current.1].unwrap();
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
+
+/// Animates `pos` as the frontier node currently being expanded (leaving the start cell
+/// alone); `mark_visited` settles it back to `Visited` once the caller is done with it.
+fn mark_current(maze: &mut Maze, pos: (usize, usize)) {
+    if pos != maze.start {
+        maze.grid[pos.0][pos.1] = Cell::Current;
+    }
+    maze.display();
+    thread::sleep(Duration::from_millis(20));
+}
+
+fn mark_visited(maze: &mut Maze, pos: (usize, usize)) {
+    if pos != maze.start {
+        maze.grid[pos.0][pos.1] = Cell::Visited;
+    }
+}
+
+fn mark_solution(maze: &mut Maze, path: &[(usize, usize)]) {
+    for &(x, y) in path {
+        maze.grid[x][y] = Cell::Solution;
+    }
+    maze.display();
+}
+
+trait Solver {
+    fn solve(&self, maze: &mut Maze) -> Option<Vec<(usize, usize)>>;
+}
+
+/// Breadth-first search. Unit-cost grid, so this is already the shortest path by cell count.
+struct BfsSolver;
+
+impl Solver for BfsSolver {
+    fn solve(&self, maze: &mut Maze) -> Option<Vec<(usize, usize)>> {
+        let mut visited = vec![vec![false; maze.size]; maze.size];
+        let mut came_from = vec![vec![None; maze.size]; maze.size];
+        let mut queue = VecDeque::new();
+
+        visited[maze.start.0][maze.start.1] = true;
+        queue.push_back(maze.start);
+
+        while let Some(current) = queue.pop_front() {
+            mark_current(maze, current);
+
+            if current == maze.goal {
+                let path = reconstruct_path(&came_from, maze.start, maze.goal);
+                mark_solution(maze, &path);
+                return Some(path);
+            }
+
+            for next in maze.neighbors(current) {
+                if !visited[next.0][next.1] {
+                    visited[next.0][next.1] = true;
+                    came_from[next.0][next.1] = Some(current);
+                    queue.push_back(next);
+                }
+            }
+
+            mark_visited(maze, current);
+        }
+        None
+    }
+}
+
+/// Cost-aware search via a binary heap keyed on `g`, relaxing each neighbor by its terrain
+/// weight rather than a flat 1, so it routes around mud instead of just minimizing hop count.
+struct DijkstraSolver;
+
+impl Solver for DijkstraSolver {
+    fn solve(&self, maze: &mut Maze) -> Option<Vec<(usize, usize)>> {
+        let mut dist = vec![vec![usize::MAX; maze.size]; maze.size];
+        let mut came_from = vec![vec![None; maze.size]; maze.size];
+        let mut closed = vec![vec![false; maze.size]; maze.size];
+        let mut heap = BinaryHeap::new();
+
+        dist[maze.start.0][maze.start.1] = 0;
+        heap.push(Reverse((0usize, maze.start)));
+
+        while let Some(Reverse((g, current))) = heap.pop() {
+            if closed[current.0][current.1] {
+                continue;
+            }
+            closed[current.0][current.1] = true;
+
+            mark_current(maze, current);
+
+            if current == maze.goal {
+                let path = reconstruct_path(&came_from, maze.start, maze.goal);
+                mark_solution(maze, &path);
+                return Some(path);
+            }
+
+            for next in maze.neighbors(current) {
+                let next_g = g + maze.weights[next.0][next.1];
+                if next_g < dist[next.0][next.1] {
+                    dist[next.0][next.1] = next_g;
+                    came_from[next.0][next.1] = Some(current);
+                    heap.push(Reverse((next_g, next)));
+                }
+            }
+
+            mark_visited(maze, current);
+        }
+        None
+    }
+}
+
+/// A* with the binary heap keyed on `f = g + h`, using `Maze::heuristic` (Manhattan, or octile
+/// when diagonal moves are enabled) as the heuristic. Relaxes by terrain weight like `DijkstraSolver`, which stays admissible since every weight
+/// is >= 1, the step cost the heuristic already assumes.
+struct AStarSolver;
+
+impl Solver for AStarSolver {
+    fn solve(&self, maze: &mut Maze) -> Option<Vec<(usize, usize)>> {
+        let mut g_score = vec![vec![usize::MAX; maze.size]; maze.size];
+        let mut came_from = vec![vec![None; maze.size]; maze.size];
+        let mut closed = vec![vec![false; maze.size]; maze.size];
+        let mut heap = BinaryHeap::new();
+
+        g_score[maze.start.0][maze.start.1] = 0;
+        heap.push(Reverse((maze.heuristic(maze.start), maze.start)));
+
+        while let Some(Reverse((_, current))) = heap.pop() {
+            if closed[current.0][current.1] {
+                continue;
+            }
+            closed[current.0][current.1] = true;
+
+            mark_current(maze, current);
+
+            if current == maze.goal {
+                let path = reconstruct_path(&came_from, maze.start, maze.goal);
+                mark_solution(maze, &path);
+                return Some(path);
+            }
+
+            let g = g_score[current.0][current.1];
+            for next in maze.neighbors(current) {
+                let next_g = g + maze.weights[next.0][next.1];
+                if next_g < g_score[next.0][next.1] {
+                    g_score[next.0][next.1] = next_g;
+                    came_from[next.0][next.1] = Some(current);
+                    let f = next_g + maze.heuristic(next);
+                    heap.push(Reverse((f, next)));
+                }
+            }
+
+            mark_visited(maze, current);
+        }
+        None
+    }
+}
+
+/// Marks `pos` as belonging to one side's frontier (unless it's `start`/`goal`, which always
+/// keep their own markers) and animates the step.
+fn mark_frontier(maze: &mut Maze, pos: (usize, usize), marker: Cell) {
+    if pos != maze.start && pos != maze.goal {
+        maze.grid[pos.0][pos.1] = marker;
+    }
+    maze.display();
+    thread::sleep(Duration::from_millis(20));
+}
+
+/// Expands every node currently queued in one side's BFS frontier (i.e. one full layer),
+/// returning the first cell found to already belong to the other side's visited set.
+fn expand_layer(
+    maze: &mut Maze,
+    frontier: &mut VecDeque<(usize, usize)>,
+    visited: &mut [Vec<bool>],
+    came_from: &mut [Vec<Option<(usize, usize)>>],
+    other_visited: &[Vec<bool>],
+    marker: Cell,
+) -> Option<(usize, usize)> {
+    for _ in 0..frontier.len() {
+        let current = frontier.pop_front().unwrap();
+        mark_frontier(maze, current, marker);
+
+        if other_visited[current.0][current.1] {
+            return Some(current);
+        }
+
+        for next in maze.neighbors(current) {
+            if !visited[next.0][next.1] {
+                visited[next.0][next.1] = true;
+                came_from[next.0][next.1] = Some(current);
+                frontier.push_back(next);
+
+                if other_visited[next.0][next.1] {
+                    return Some(next);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Joins the start-side and goal-side predecessor chains at `meeting` into one
+/// start-to-goal path, reversing the goal-side half.
+fn stitch_paths(
+    came_from_fwd: &[Vec<Option<(usize, usize)>>],
+    came_from_bwd: &[Vec<Option<(usize, usize)>>],
+    start: (usize, usize),
+    goal: (usize, usize),
+    meeting: (usize, usize),
+) -> Vec<(usize, usize)> {
+    let mut path = vec![meeting];
+    let mut current = meeting;
+    while current != start {
+        current = came_from_fwd[current.0][current.1].unwrap();
+        path.push(current);
+    }
+    path.reverse();
+
+    let mut current = meeting;
+    while current != goal {
+        current = came_from_bwd[current.0][current.1].unwrap();
+        path.push(current);
+    }
+    path
+}
+
+/// Two BFS frontiers, one from `start` and one from `goal`, expanding a layer at a time
+/// until they meet.
+struct BidirectionalSolver;
+
+impl Solver for BidirectionalSolver {
+    fn solve(&self, maze: &mut Maze) -> Option<Vec<(usize, usize)>> {
+        let mut visited_fwd = vec![vec![false; maze.size]; maze.size];
+        let mut visited_bwd = vec![vec![false; maze.size]; maze.size];
+        let mut came_from_fwd = vec![vec![None; maze.size]; maze.size];
+        let mut came_from_bwd = vec![vec![None; maze.size]; maze.size];
+        let mut frontier_fwd = VecDeque::new();
+        let mut frontier_bwd = VecDeque::new();
+
+        visited_fwd[maze.start.0][maze.start.1] = true;
+        frontier_fwd.push_back(maze.start);
+        visited_bwd[maze.goal.0][maze.goal.1] = true;
+        frontier_bwd.push_back(maze.goal);
+
+        while !frontier_fwd.is_empty() || !frontier_bwd.is_empty() {
+            if let Some(meeting) = expand_layer(
+                maze, &mut frontier_fwd, &mut visited_fwd, &mut came_from_fwd, &visited_bwd,
+                Cell::Visited,
+            ) {
+                let path = stitch_paths(&came_from_fwd, &came_from_bwd, maze.start, maze.goal, meeting);
+                mark_solution(maze, &path);
+                return Some(path);
+            }
+
+            if let Some(meeting) = expand_layer(
+                maze, &mut frontier_bwd, &mut visited_bwd, &mut came_from_bwd, &visited_fwd,
+                Cell::VisitedBackward,
+            ) {
+                let path = stitch_paths(&came_from_fwd, &came_from_bwd, maze.start, maze.goal, meeting);
+                mark_solution(maze, &path);
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
 #[derive(Clone)]
 struct SearchState {
     path: Vec<(usize, usize)>,
-    visited: Vec<Vec<bool>>,
 }
 
 enum SearchResult {
@@ -160,28 +683,28 @@ enum SearchResult {
     NewBound(usize),
 }
 
+/// Iterative-deepening A*. Re-runs a depth-first search with a growing `f`-bound until the
+/// bound reaches the cost of the optimal path, which IDA* then finds on that final iteration.
 fn ida_star(maze: &mut Maze) -> Option<Vec<(usize, usize)>> {
-    let initial_estimate = maze.manhattan_distance(maze.start);
-    let mut bound = initial_estimate * 3;  // Start with a more generous bound
+    let mut bound = maze.heuristic(maze.start);
     let mut state = SearchState {
         path: vec![maze.start],
-        visited: vec![vec![false; maze.size]; maze.size],
     };
 
-    while bound < maze.size * maze.size {  // Upper limit to prevent infinite loops
+    loop {
         match search(maze, 0, bound, &mut state) {
             SearchResult::Found(solution) => return Some(solution),
             SearchResult::NewBound(new_bound) => {
                 if new_bound == usize::MAX {
-                    bound += maze.size;  // More aggressive bound increase
-                } else {
-                    bound = new_bound + maze.size/2;  // Significant increase to reduce iterations
+                    return None;
                 }
-                state.path = vec![maze.start];  // Keep visited cells marked
+                // The next bound is exactly the smallest f that exceeded the previous one, so
+                // every iteration makes progress without overshooting past the optimal cost.
+                bound = new_bound;
+                state.path = vec![maze.start];
             }
         }
     }
-    None
 }
 
 fn search(
@@ -191,7 +714,7 @@ fn search(
     state: &mut SearchState,
 ) -> SearchResult {
     let current = *state.path.last().unwrap();
-    let h = maze.manhattan_distance(current);
+    let h = maze.heuristic(current);
     let f = g + h;
 
     // Show current position
@@ -217,19 +740,11 @@ fn search(
         return SearchResult::Found(state.path.clone());
     }
 
-    // Mark current cell as visited in state tracking
-    state.visited[current.0][current.1] = true;
-
     // Get all possible moves and sort by estimated total cost
     let mut moves = Vec::new();
-    for (dx, dy) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
-        let nx = (current.0 as isize + dx) as usize;
-        let ny = (current.1 as isize + dy) as usize;
-        
-        if nx < maze.size && ny < maze.size && 
-           maze.grid[nx][ny] != Cell::Wall && 
-           !state.visited[nx][ny] {
-            let move_h = maze.manhattan_distance((nx, ny));
+    for (nx, ny) in maze.neighbors(current) {
+        if !state.path.contains(&(nx, ny)) {
+            let move_h = maze.heuristic((nx, ny));
             let move_g = g + 1;
             let move_f = move_g + move_h;
             moves.push((move_f, (nx, ny)));
@@ -242,14 +757,14 @@ fn search(
     for (_, (nx, ny)) in moves {
         if !state.path.contains(&(nx, ny)) {
             state.path.push((nx, ny));
-            
+
             match search(maze, g + 1, bound, state) {
                 SearchResult::Found(solution) => return SearchResult::Found(solution),
                 SearchResult::NewBound(new_bound) => {
                     min_bound = min_bound.min(new_bound);
                 }
             }
-            
+
             state.path.pop();
         }
     }
@@ -258,32 +773,179 @@ fn search(
     if !is_start {
         maze.grid[current.0][current.1] = Cell::Visited;
     }
-    
+
     SearchResult::NewBound(min_bound)
 }
 
+struct IdaStarSolver;
+
+impl Solver for IdaStarSolver {
+    fn solve(&self, maze: &mut Maze) -> Option<Vec<(usize, usize)>> {
+        ida_star(maze)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SolverKind {
+    Bfs,
+    Dijkstra,
+    AStar,
+    Ida,
+    Bidirectional,
+}
+
+impl SolverKind {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "bfs" => Some(SolverKind::Bfs),
+            "dijkstra" => Some(SolverKind::Dijkstra),
+            "astar" => Some(SolverKind::AStar),
+            "ida" => Some(SolverKind::Ida),
+            "bidirectional" => Some(SolverKind::Bidirectional),
+            _ => None,
+        }
+    }
+
+    fn build(self) -> Box<dyn Solver> {
+        match self {
+            SolverKind::Bfs => Box::new(BfsSolver),
+            SolverKind::Dijkstra => Box::new(DijkstraSolver),
+            SolverKind::AStar => Box::new(AStarSolver),
+            SolverKind::Ida => Box::new(IdaStarSolver),
+            SolverKind::Bidirectional => Box::new(BidirectionalSolver),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum GeneratorKind {
+    Dfs,
+    Prim,
+    Kruskal,
+}
+
+impl GeneratorKind {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "dfs" => Some(GeneratorKind::Dfs),
+            "prim" => Some(GeneratorKind::Prim),
+            "kruskal" => Some(GeneratorKind::Kruskal),
+            _ => None,
+        }
+    }
+
+    fn build(self) -> Box<dyn Generator> {
+        match self {
+            GeneratorKind::Dfs => Box::new(DfsGenerator),
+            GeneratorKind::Prim => Box::new(PrimGenerator),
+            GeneratorKind::Kruskal => Box::new(KruskalGenerator),
+        }
+    }
+}
+
+struct Config {
+    size: usize,
+    solver: SolverKind,
+    diagonal: bool,
+    image: Option<String>,
+    generator: GeneratorKind,
+    seed: Option<u64>,
+}
+
+fn parse_args(args: &[String]) -> Config {
+    let mut size = 15;
+    let mut solver = SolverKind::Bfs;
+    let mut diagonal = false;
+    let mut image = None;
+    let mut generator = GeneratorKind::Dfs;
+    let mut seed = None;
+    let mut size_set = false;
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--solver" => {
+                i += 1;
+                if let Some(value) = args.get(i) {
+                    if let Some(kind) = SolverKind::from_str(value) {
+                        solver = kind;
+                    }
+                }
+            }
+            "--diagonal" => {
+                diagonal = true;
+            }
+            "--bidirectional" => {
+                solver = SolverKind::Bidirectional;
+            }
+            "--image" => {
+                i += 1;
+                if let Some(value) = args.get(i) {
+                    image = Some(value.clone());
+                }
+            }
+            "--generator" => {
+                i += 1;
+                if let Some(value) = args.get(i) {
+                    if let Some(kind) = GeneratorKind::from_str(value) {
+                        generator = kind;
+                    }
+                }
+            }
+            "--seed" => {
+                i += 1;
+                if let Some(value) = args.get(i) {
+                    if let Ok(parsed) = value.parse() {
+                        seed = Some(parsed);
+                    }
+                }
+            }
+            arg if !size_set => {
+                if let Ok(parsed) = arg.parse() {
+                    size = parsed;
+                    size_set = true;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Config { size, solver, diagonal, image, generator, seed }
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let size = if args.len() > 1 {
-        args[1].parse().unwrap_or(15)
-    } else {
-        15
+    let config = parse_args(&args);
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
     };
 
-    let mut maze = Maze::new(size);
-    maze.generate();
+    let mut maze = Maze::new(config.size, config.diagonal);
+    maze.generate_with(config.generator.build().as_ref(), &mut rng);
 
     // Clear screen and hide cursor
     print!("\x1B[2J\x1B[?25l");
     std::io::stdout().flush().unwrap();
 
-    if let Some(solution) = ida_star(&mut maze) {
+    let solver = config.solver.build();
+    if let Some(solution) = solver.solve(&mut maze) {
         println!("\nSolution found! Path length: {}", solution.len());
         thread::sleep(Duration::from_secs(2));
     } else {
         println!("No solution found!");
     }
 
+    if let Some(path) = &config.image {
+        const CELL_PX: usize = 10;
+        match maze.write_image(path, CELL_PX) {
+            Ok(()) => println!("Wrote maze image to {}", path),
+            Err(e) => eprintln!("Failed to write image to {}: {}", path, e),
+        }
+    }
+
     // Show cursor again
     print!("\x1B[?25h");
     std::io::stdout().flush().unwrap();